@@ -57,16 +57,240 @@ where
     from_value(cx, unwrapped)
 }
 
+/// One step of the path to a value being deserialized, rendered by
+/// [`render_path`] into e.g. `config.servers[2].port` for error messages.
+#[doc(hidden)]
+#[derive(Clone, Debug)]
+enum PathSegment {
+    Key(String),
+    Index(u32),
+}
+
+#[doc(hidden)]
+fn render_path(path: &[PathSegment]) -> String {
+    let mut rendered = String::new();
+    for (i, segment) in path.iter().enumerate() {
+        match segment {
+            PathSegment::Key(key) => {
+                if i > 0 {
+                    rendered.push('.');
+                }
+                rendered.push_str(key);
+            }
+            PathSegment::Index(idx) => {
+                rendered.push('[');
+                rendered.push_str(&idx.to_string());
+                rendered.push(']');
+            }
+        }
+    }
+    rendered
+}
+
+/// True if `v` is an integral `f64` (no fractional part) within
+/// `[min, max]`, used by the fixed-width integer deserialize methods
+/// whose bounds are exactly representable as `f64` (`i8`/`u8` through
+/// `i32`/`u32`).
+#[doc(hidden)]
+fn integral_in_range(v: f64, min: f64, max: f64) -> bool {
+    v.trunc() == v && v >= min && v <= max
+}
+
+/// True if `v` is an integral `f64` within `[min, max_exclusive)` (the
+/// upper bound is exclusive), for widths (`i64`/`u64`/`i128`/`u128`)
+/// whose exact upper bound isn't itself representable as an `f64`.
+#[doc(hidden)]
+fn integral_in_half_open_range(v: f64, min: f64, max_exclusive: f64) -> bool {
+    v.trunc() == v && v >= min && v < max_exclusive
+}
+
+/// Annotates `err` with the JS-side location it failed at, unless it's
+/// already been annotated by a deeper frame on the way up the call stack.
+#[doc(hidden)]
+fn attach_path(err: LibError, path: &[PathSegment]) -> LibError {
+    if path.is_empty() {
+        return err;
+    }
+    let message = err.to_string();
+    if message.starts_with("at ") {
+        return err;
+    }
+    serde::de::Error::custom(format!("at {}: {}", render_path(path), message))
+}
+
 #[doc(hidden)]
 pub struct Deserializer<'a, 'j, C: Context<'j> + 'a> {
     cx: &'a mut C,
     input: Handle<'j, JsValue>,
+    path: Vec<PathSegment>,
 }
 
 #[doc(hidden)]
 impl<'a, 'j, C: Context<'j>> Deserializer<'a, 'j, C> {
     fn new(cx: &'a mut C, input: Handle<'j, JsValue>) -> Self {
-        Deserializer { cx, input }
+        Deserializer {
+            cx,
+            input,
+            path: Vec::new(),
+        }
+    }
+
+    fn with_path(cx: &'a mut C, input: Handle<'j, JsValue>, path: Vec<PathSegment>) -> Self {
+        Deserializer { cx, input, path }
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn number_value(&mut self, expected: &'static str) -> LibResult<f64> {
+        match self.input.downcast::<JsNumber, C>(self.cx) {
+            Ok(val) => Ok(val.value(self.cx)),
+            Err(_) => Err(self.type_mismatch(expected)),
+        }
+    }
+
+    #[cfg(feature = "legacy-runtime")]
+    fn number_value(&mut self, expected: &'static str) -> LibResult<f64> {
+        match self.input.downcast::<JsNumber>() {
+            Ok(val) => Ok(val.value()),
+            Err(_) => Err(self.type_mismatch(expected)),
+        }
+    }
+
+    /// Builds an `invalid_type` error naming the actual kind of JS value
+    /// found, for deserialize methods whose input turned out not to be the
+    /// kind they expect (a `Number`/`BigInt`, a plain object, an array).
+    /// Used instead of letting the downcast throw, which would surface a
+    /// raw neon `TypeError` rather than a proper serde type-mismatch error.
+    #[cfg(feature = "napi-6")]
+    fn type_mismatch(&mut self, expected: &'static str) -> LibError {
+        let kind = if self.input.downcast::<JsString, C>(self.cx).is_ok() {
+            "string"
+        } else if self.input.downcast::<JsBoolean, C>(self.cx).is_ok() {
+            "boolean"
+        } else if self.input.downcast::<JsNull, C>(self.cx).is_ok()
+            || self.input.downcast::<JsUndefined, C>(self.cx).is_ok()
+        {
+            "null"
+        } else if self.input.downcast::<JsArray, C>(self.cx).is_ok() {
+            "array"
+        } else if self.input.downcast::<JsObject, C>(self.cx).is_ok() {
+            "object"
+        } else {
+            "JsValue"
+        };
+        serde::de::Error::invalid_type(Unexpected::Other(kind), &expected)
+    }
+
+    #[cfg(feature = "legacy-runtime")]
+    fn type_mismatch(&mut self, expected: &'static str) -> LibError {
+        let kind = if self.input.downcast::<JsString>().is_ok() {
+            "string"
+        } else if self.input.downcast::<JsBoolean>().is_ok() {
+            "boolean"
+        } else if self.input.downcast::<JsNull>().is_ok() || self.input.downcast::<JsUndefined>().is_ok()
+        {
+            "null"
+        } else if self.input.downcast::<JsArray>().is_ok() {
+            "array"
+        } else if self.input.downcast::<JsObject>().is_ok() {
+            "object"
+        } else {
+            "JsValue"
+        };
+        serde::de::Error::invalid_type(Unexpected::Other(kind), &expected)
+    }
+
+    /// Returns the epoch millisecond value if the input is a `JsDate`, so
+    /// numeric deserialization targets can read it directly instead of going
+    /// through the RFC 3339 string `deserialize_any` produces.
+    #[cfg(feature = "napi-6")]
+    fn date_value(&mut self) -> LibResult<Option<f64>> {
+        if let Ok(date) = self.input.downcast::<JsDate, C>(self.cx) {
+            Ok(Some(date.value(self.cx)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[cfg(feature = "legacy-runtime")]
+    fn date_value(&mut self) -> LibResult<Option<f64>> {
+        Ok(None)
+    }
+
+    /// Returns the numeric value of a `BigInt` input as an `f64`, for
+    /// targets narrow enough (`u8..=u32`/`i8..=i32`) that going through
+    /// `f64` is still exact, so a `BigInt`-valued field deserializes the
+    /// same as a `Number`-valued one instead of erroring on the downcast.
+    #[cfg(feature = "napi-6")]
+    fn narrow_bigint_value(&mut self) -> LibResult<Option<f64>> {
+        if let Ok(val) = self.input.downcast::<JsBigInt, C>(self.cx) {
+            let (v, lossless) = val.to_i64(self.cx);
+            if lossless {
+                return Ok(Some(v as f64));
+            }
+            let (v, lossless) = val.to_u64(self.cx);
+            ensure!(
+                lossless,
+                errors::NumberTooLargeSnafu {
+                    name: "BigInt value does not fit in i64/u64"
+                }
+            );
+            Ok(Some(v as f64))
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[cfg(feature = "legacy-runtime")]
+    fn narrow_bigint_value(&mut self) -> LibResult<Option<f64>> {
+        Ok(None)
+    }
+
+    /// Returns the exact `i64` value of a `BigInt` input, used by
+    /// `deserialize_i64` so a `BigInt` round-trips precisely instead of
+    /// losing precision through an `f64` intermediate.
+    #[cfg(feature = "napi-6")]
+    fn bigint_i64_value(&mut self) -> LibResult<Option<i64>> {
+        if let Ok(val) = self.input.downcast::<JsBigInt, C>(self.cx) {
+            let (v, lossless) = val.to_i64(self.cx);
+            ensure!(
+                lossless,
+                errors::NumberTooLargeSnafu {
+                    name: "BigInt value does not fit in i64"
+                }
+            );
+            Ok(Some(v))
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[cfg(feature = "legacy-runtime")]
+    fn bigint_i64_value(&mut self) -> LibResult<Option<i64>> {
+        Ok(None)
+    }
+
+    /// Returns the exact `u64` value of a `BigInt` input, used by
+    /// `deserialize_u64` so a `BigInt` round-trips precisely instead of
+    /// losing precision through an `f64` intermediate.
+    #[cfg(feature = "napi-6")]
+    fn bigint_u64_value(&mut self) -> LibResult<Option<u64>> {
+        if let Ok(val) = self.input.downcast::<JsBigInt, C>(self.cx) {
+            let (v, lossless) = val.to_u64(self.cx);
+            ensure!(
+                lossless,
+                errors::NumberTooLargeSnafu {
+                    name: "BigInt value does not fit in u64"
+                }
+            );
+            Ok(Some(v))
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[cfg(feature = "legacy-runtime")]
+    fn bigint_u64_value(&mut self) -> LibResult<Option<u64>> {
+        Ok(None)
     }
 }
 
@@ -95,14 +319,76 @@ impl<'x, 'd, 'a, 'j, C: Context<'j>> serde::de::Deserializer<'x>
             } else {
                 visitor.visit_f64(v)
             }
+        } else if let Ok(val) = self.input.downcast::<JsBigInt, C>(self.cx) {
+            let (v, lossless) = val.to_i64(self.cx);
+            if lossless {
+                return visitor.visit_i64(v);
+            }
+            let (v, lossless) = val.to_u64(self.cx);
+            if lossless {
+                return visitor.visit_u64(v);
+            }
+            let (v, lossless) = val.to_i128(self.cx);
+            if lossless {
+                return visitor.visit_i128(v);
+            }
+            let (v, lossless) = val.to_u128(self.cx);
+            ensure!(
+                lossless,
+                errors::NumberTooLargeSnafu {
+                    name: "BigInt value does not fit in u128"
+                }
+            );
+            visitor.visit_u128(v)
+        } else if let Ok(val) = self.input.downcast::<JsDate, C>(self.cx) {
+            // Dates have no direct serde counterpart, so by default we hand the
+            // visitor an RFC 3339 string, which `chrono`/`time` types deserialize
+            // from directly. Numeric targets go through `deserialize_i64`/
+            // `deserialize_f64`, which read the raw epoch millisecond value instead.
+            let millis = val.value(self.cx);
+            ensure!(
+                !millis.is_nan(),
+                errors::InvalidDateSnafu {
+                    reason: "Date value is NaN"
+                }
+            );
+            let to_iso: Handle<JsFunction> = val.get(self.cx, "toISOString")?;
+            let iso: Handle<JsString> = to_iso.call_with(self.cx).this(val).apply(self.cx)?;
+            visitor.visit_string(iso.value(self.cx))
         } else if let Ok(_val) = self.input.downcast::<JsBuffer, C>(self.cx) {
             self.deserialize_bytes(visitor)
+        } else if let Ok(_val) = self.input.downcast::<JsArrayBuffer, C>(self.cx) {
+            self.deserialize_bytes(visitor)
+        } else if let Ok(_val) = self.input.downcast::<JsUint8Array, C>(self.cx) {
+            self.deserialize_bytes(visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsInt8Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsUint16Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsInt16Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsUint32Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsInt32Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsFloat32Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsFloat64Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
         } else if let Ok(val) = self.input.downcast::<JsArray, C>(self.cx) {
-            let mut deserializer = JsArrayAccess::new(self.cx, val);
+            let mut deserializer = JsArrayAccess::new(self.cx, val, self.path.clone());
             visitor.visit_seq(&mut deserializer)
         } else if let Ok(val) = self.input.downcast::<JsObject, C>(self.cx) {
-            let mut deserializer = JsObjectAccess::new(self.cx, val)?;
-            visitor.visit_map(&mut deserializer)
+            if is_instance_of(self.cx, val, "Map")? {
+                let mut deserializer = JsMapAccess::new(self.cx, val, self.path.clone())?;
+                visitor.visit_map(&mut deserializer)
+            } else if is_instance_of(self.cx, val, "Set")? {
+                let mut deserializer = JsSetAccess::new(self.cx, val, self.path.clone())?;
+                visitor.visit_seq(&mut deserializer)
+            } else {
+                let mut deserializer = JsObjectAccess::new(self.cx, val, self.path.clone())?;
+                visitor.visit_map(&mut deserializer)
+            }
         } else {
             errors::NotImplemented {
                 name: "unimplemented Deserializer::Deserializer",
@@ -135,10 +421,10 @@ impl<'x, 'd, 'a, 'j, C: Context<'j>> serde::de::Deserializer<'x>
         } else if let Ok(_val) = self.input.downcast::<JsBuffer>() {
             self.deserialize_bytes(visitor)
         } else if let Ok(val) = self.input.downcast::<JsArray>() {
-            let mut deserializer = JsArrayAccess::new(self.cx, val);
+            let mut deserializer = JsArrayAccess::new(self.cx, val, self.path.clone());
             visitor.visit_seq(&mut deserializer)
         } else if let Ok(val) = self.input.downcast::<JsObject>() {
-            let mut deserializer = JsObjectAccess::new(self.cx, val)?;
+            let mut deserializer = JsObjectAccess::new(self.cx, val, self.path.clone())?;
             visitor.visit_map(&mut deserializer)
         } else {
             errors::NotImplemented {
@@ -153,21 +439,17 @@ impl<'x, 'd, 'a, 'j, C: Context<'j>> serde::de::Deserializer<'x>
     where
         V: Visitor<'x>,
     {
-        let buff = self
-            .input
-            .downcast::<JsBuffer, C>(self.cx)
-            .or_throw(self.cx)?;
-        let guard = self.cx.lock();
-        let copy = buff.try_borrow(&guard);
-        match copy {
-            Ok(buff_copy) => {
-                let copy_vec = buff_copy.deref();
-                visitor.visit_bytes(copy_vec)
-            }
-            Err(_) => errors::NotImplemented {
+        if let Ok(buff) = self.input.downcast::<JsBuffer, C>(self.cx) {
+            visitor.visit_bytes(&copy_byte_buffer(self.cx, buff)?)
+        } else if let Ok(buff) = self.input.downcast::<JsArrayBuffer, C>(self.cx) {
+            visitor.visit_bytes(&copy_byte_buffer(self.cx, buff)?)
+        } else if let Ok(buff) = self.input.downcast::<JsUint8Array, C>(self.cx) {
+            visitor.visit_bytes(&copy_byte_buffer(self.cx, buff)?)
+        } else {
+            errors::NotImplemented {
                 name: "unimplemented Deserializer::deserialize_bytes",
             }
-            .fail()?,
+            .fail()?
         }
     }
 
@@ -176,21 +458,17 @@ impl<'x, 'd, 'a, 'j, C: Context<'j>> serde::de::Deserializer<'x>
     where
         V: Visitor<'x>,
     {
-        let buff = self
-            .input
-            .downcast::<JsBuffer, C>(self.cx)
-            .or_throw(self.cx)?;
-        let guard = self.cx.lock();
-        let copy = buff.try_borrow(&guard);
-        match copy {
-            Ok(buff_copy) => {
-                let copy_vec = buff_copy.deref();
-                visitor.visit_byte_buf(Vec::from(copy_vec))
-            }
-            Err(_) => errors::NotImplemented {
+        if let Ok(buff) = self.input.downcast::<JsBuffer, C>(self.cx) {
+            visitor.visit_byte_buf(copy_byte_buffer(self.cx, buff)?)
+        } else if let Ok(buff) = self.input.downcast::<JsArrayBuffer, C>(self.cx) {
+            visitor.visit_byte_buf(copy_byte_buffer(self.cx, buff)?)
+        } else if let Ok(buff) = self.input.downcast::<JsUint8Array, C>(self.cx) {
+            visitor.visit_byte_buf(copy_byte_buffer(self.cx, buff)?)
+        } else {
+            errors::NotImplemented {
                 name: "unimplemented Deserializer::deserialize_byte_buf",
             }
-            .fail()?,
+            .fail()?
         }
     }
 
@@ -208,6 +486,76 @@ impl<'x, 'd, 'a, 'j, C: Context<'j>> serde::de::Deserializer<'x>
         }
     }
 
+    #[cfg(feature = "napi-6")]
+    fn deserialize_map<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        let val = match self.input.downcast::<JsObject, C>(self.cx) {
+            Ok(val) => val,
+            Err(_) => return Err(self.type_mismatch("a map")),
+        };
+        if is_instance_of(self.cx, val, "Map")? {
+            let mut deserializer = JsMapAccess::new(self.cx, val, self.path.clone())?;
+            visitor.visit_map(&mut deserializer)
+        } else if is_instance_of(self.cx, val, "Set")? {
+            Err(serde::de::Error::invalid_type(
+                Unexpected::Other("set"),
+                &"a map",
+            ))
+        } else {
+            let mut deserializer = JsObjectAccess::new(self.cx, val, self.path.clone())?;
+            visitor.visit_map(&mut deserializer)
+        }
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        if let Ok(val) = self.input.downcast::<JsArray, C>(self.cx) {
+            let mut deserializer = JsArrayAccess::new(self.cx, val, self.path.clone());
+            visitor.visit_seq(&mut deserializer)
+        } else if let Ok(buff) = self.input.downcast::<JsBuffer, C>(self.cx) {
+            typed_array_seq(self.cx, buff, self.path.clone(), visitor)
+        } else if let Ok(buff) = self.input.downcast::<JsArrayBuffer, C>(self.cx) {
+            typed_array_seq(self.cx, buff, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsUint8Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsInt8Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsUint16Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsInt16Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsUint32Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsInt32Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsFloat32Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else if let Ok(arr) = self.input.downcast::<JsFloat64Array, C>(self.cx) {
+            typed_array_seq(self.cx, arr, self.path.clone(), visitor)
+        } else {
+            let val = match self.input.downcast::<JsObject, C>(self.cx) {
+                Ok(val) => val,
+                Err(_) => {
+                    return Err(self.type_mismatch("array, Set, ArrayBuffer, or typed array"))
+                }
+            };
+            if is_instance_of(self.cx, val, "Set")? {
+                let mut deserializer = JsSetAccess::new(self.cx, val, self.path.clone())?;
+                visitor.visit_seq(&mut deserializer)
+            } else {
+                Err(serde::de::Error::invalid_type(
+                    Unexpected::Other("object"),
+                    &"array, Set, ArrayBuffer, or typed array",
+                ))
+            }
+        }
+    }
+
     #[cfg(feature = "legacy-runtime")]
     fn deserialize_bytes<V>(self, visitor: V) -> Result<V::Value, Self::Error>
     where
@@ -314,9 +662,230 @@ impl<'x, 'd, 'a, 'j, C: Context<'j>> serde::de::Deserializer<'x>
         visitor.visit_unit()
     }
 
+    #[cfg(feature = "napi-6")]
+    fn deserialize_i128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        match self.input.downcast::<JsBigInt, C>(self.cx) {
+            Ok(val) => {
+                let (v, lossless) = val.to_i128(self.cx);
+                ensure!(
+                    lossless,
+                    errors::NumberTooLargeSnafu {
+                        name: "BigInt value does not fit in i128"
+                    }
+                );
+                visitor.visit_i128(v)
+            }
+            Err(_) => {
+                let v = self.number_value("i128")?;
+                if integral_in_half_open_range(v, i64::MIN as f64, 2f64.powi(63)) {
+                    visitor.visit_i128(v as i128)
+                } else {
+                    Err(serde::de::Error::invalid_type(Unexpected::Float(v), &"i128"))
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "napi-6")]
+    fn deserialize_u128<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        match self.input.downcast::<JsBigInt, C>(self.cx) {
+            Ok(val) => {
+                let (v, lossless) = val.to_u128(self.cx);
+                ensure!(
+                    lossless,
+                    errors::NumberTooLargeSnafu {
+                        name: "BigInt value does not fit in u128"
+                    }
+                );
+                visitor.visit_u128(v)
+            }
+            Err(_) => {
+                let v = self.number_value("u128")?;
+                if integral_in_half_open_range(v, 0.0, 2f64.powi(64)) {
+                    visitor.visit_u128(v as u128)
+                } else {
+                    Err(serde::de::Error::invalid_type(Unexpected::Float(v), &"u128"))
+                }
+            }
+        }
+    }
+
+    fn deserialize_u8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        let v = match self.narrow_bigint_value()? {
+            Some(v) => v,
+            None => self.number_value("u8")?,
+        };
+        if integral_in_range(v, f64::from(u8::MIN), f64::from(u8::MAX)) {
+            visitor.visit_u64(v as u64)
+        } else {
+            Err(serde::de::Error::invalid_type(Unexpected::Float(v), &"u8"))
+        }
+    }
+
+    fn deserialize_u16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        let v = match self.narrow_bigint_value()? {
+            Some(v) => v,
+            None => self.number_value("u16")?,
+        };
+        if integral_in_range(v, f64::from(u16::MIN), f64::from(u16::MAX)) {
+            visitor.visit_u64(v as u64)
+        } else {
+            Err(serde::de::Error::invalid_type(Unexpected::Float(v), &"u16"))
+        }
+    }
+
+    fn deserialize_u32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        let v = match self.narrow_bigint_value()? {
+            Some(v) => v,
+            None => self.number_value("u32")?,
+        };
+        if integral_in_range(v, f64::from(u32::MIN), f64::from(u32::MAX)) {
+            visitor.visit_u64(v as u64)
+        } else {
+            Err(serde::de::Error::invalid_type(Unexpected::Float(v), &"u32"))
+        }
+    }
+
+    fn deserialize_u64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        if let Some(v) = self.bigint_u64_value()? {
+            return visitor.visit_u64(v);
+        }
+        let v = self.number_value("u64")?;
+        if integral_in_half_open_range(v, 0.0, 2f64.powi(64)) {
+            visitor.visit_u64(v as u64)
+        } else {
+            Err(serde::de::Error::invalid_type(Unexpected::Float(v), &"u64"))
+        }
+    }
+
+    fn deserialize_i8<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        let v = match self.narrow_bigint_value()? {
+            Some(v) => v,
+            None => self.number_value("i8")?,
+        };
+        if integral_in_range(v, f64::from(i8::MIN), f64::from(i8::MAX)) {
+            visitor.visit_i64(v as i64)
+        } else {
+            Err(serde::de::Error::invalid_type(Unexpected::Float(v), &"i8"))
+        }
+    }
+
+    fn deserialize_i16<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        let v = match self.narrow_bigint_value()? {
+            Some(v) => v,
+            None => self.number_value("i16")?,
+        };
+        if integral_in_range(v, f64::from(i16::MIN), f64::from(i16::MAX)) {
+            visitor.visit_i64(v as i64)
+        } else {
+            Err(serde::de::Error::invalid_type(Unexpected::Float(v), &"i16"))
+        }
+    }
+
+    fn deserialize_i32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        let v = match self.narrow_bigint_value()? {
+            Some(v) => v,
+            None => self.number_value("i32")?,
+        };
+        if integral_in_range(v, f64::from(i32::MIN), f64::from(i32::MAX)) {
+            visitor.visit_i64(v as i64)
+        } else {
+            Err(serde::de::Error::invalid_type(Unexpected::Float(v), &"i32"))
+        }
+    }
+
+    fn deserialize_i64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        if let Some(v) = self.bigint_i64_value()? {
+            return visitor.visit_i64(v);
+        }
+        let v = match self.date_value()? {
+            Some(millis) => {
+                ensure!(
+                    !millis.is_nan(),
+                    errors::InvalidDateSnafu {
+                        reason: "Date value is NaN"
+                    }
+                );
+                millis
+            }
+            None => self.number_value("i64")?,
+        };
+        if integral_in_half_open_range(v, i64::MIN as f64, 2f64.powi(63)) {
+            visitor.visit_i64(v as i64)
+        } else {
+            Err(serde::de::Error::invalid_type(Unexpected::Float(v), &"i64"))
+        }
+    }
+
+    fn deserialize_f32<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        let v = self.number_value("f32")?;
+        visitor.visit_f32(v as f32)
+    }
+
+    fn deserialize_f64<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'x>,
+    {
+        let v = match self.date_value()? {
+            Some(millis) => {
+                ensure!(
+                    !millis.is_nan(),
+                    errors::InvalidDateSnafu {
+                        reason: "Date value is NaN"
+                    }
+                );
+                millis
+            }
+            None => self.number_value("f64")?,
+        };
+        visitor.visit_f64(v)
+    }
+
+    #[cfg(feature = "napi-6")]
+    serde::forward_to_deserialize_any! {
+       <V: Visitor<'x>>
+        bool char str string
+        unit unit_struct tuple tuple_struct struct identifier
+        newtype_struct
+    }
+
+    #[cfg(feature = "legacy-runtime")]
     serde::forward_to_deserialize_any! {
        <V: Visitor<'x>>
-        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bool i128 u128 char str string
         unit unit_struct seq tuple tuple_struct map struct identifier
         newtype_struct
     }
@@ -328,28 +897,31 @@ struct JsArrayAccess<'a, 'j, C: Context<'j> + 'a> {
     input: Handle<'j, JsArray>,
     idx: u32,
     len: u32,
+    path: Vec<PathSegment>,
 }
 
 #[doc(hidden)]
 impl<'a, 'j, C: Context<'j>> JsArrayAccess<'a, 'j, C> {
     #[cfg(feature = "napi-6")]
-    fn new(cx: &'a mut C, input: Handle<'j, JsArray>) -> Self {
+    fn new(cx: &'a mut C, input: Handle<'j, JsArray>, path: Vec<PathSegment>) -> Self {
         let len = input.len(cx);
         JsArrayAccess {
             cx,
             input,
             idx: 0,
             len,
+            path,
         }
     }
 
     #[cfg(feature = "legacy-runtime")]
-    fn new(cx: &'a mut C, input: Handle<'j, JsArray>) -> Self {
+    fn new(cx: &'a mut C, input: Handle<'j, JsArray>, path: Vec<PathSegment>) -> Self {
         JsArrayAccess {
             cx,
             input,
             idx: 0,
             len: input.len(),
+            path,
         }
     }
 }
@@ -366,10 +938,14 @@ impl<'x, 'a, 'j, C: Context<'j>> SeqAccess<'x> for JsArrayAccess<'a, 'j, C> {
             return Ok(None);
         }
         let v = self.input.get(self.cx, self.idx)?;
+        let mut child_path = self.path.clone();
+        child_path.push(PathSegment::Index(self.idx));
         self.idx += 1;
 
-        let mut de = Deserializer::new(self.cx, v);
-        seed.deserialize(&mut de).map(Some)
+        let mut de = Deserializer::with_path(self.cx, v, child_path.clone());
+        seed.deserialize(&mut de)
+            .map(Some)
+            .map_err(|e| attach_path(e, &child_path))
     }
 }
 
@@ -380,12 +956,13 @@ struct JsObjectAccess<'a, 'j, C: Context<'j> + 'a> {
     prop_names: Handle<'j, JsArray>,
     idx: u32,
     len: u32,
+    path: Vec<PathSegment>,
 }
 
 #[doc(hidden)]
 impl<'x, 'a, 'j, C: Context<'j>> JsObjectAccess<'a, 'j, C> {
     #[cfg(feature = "legacy-runtime")]
-    fn new(cx: &'a mut C, input: Handle<'j, JsObject>) -> LibResult<Self> {
+    fn new(cx: &'a mut C, input: Handle<'j, JsObject>, path: Vec<PathSegment>) -> LibResult<Self> {
         let prop_names = input.get_own_property_names(cx)?;
         let len = prop_names.len();
 
@@ -395,11 +972,12 @@ impl<'x, 'a, 'j, C: Context<'j>> JsObjectAccess<'a, 'j, C> {
             prop_names,
             idx: 0,
             len,
+            path,
         })
     }
 
     #[cfg(feature = "napi-6")]
-    fn new(cx: &'a mut C, input: Handle<'j, JsObject>) -> LibResult<Self> {
+    fn new(cx: &'a mut C, input: Handle<'j, JsObject>, path: Vec<PathSegment>) -> LibResult<Self> {
         let prop_names = input.get_own_property_names(cx)?;
         let len = prop_names.len(cx);
         Ok(JsObjectAccess {
@@ -408,6 +986,7 @@ impl<'x, 'a, 'j, C: Context<'j>> JsObjectAccess<'a, 'j, C> {
             prop_names,
             idx: 0,
             len,
+            path,
         })
     }
 }
@@ -446,14 +1025,257 @@ impl<'x, 'a, 'j, C: Context<'j>> MapAccess<'x> for JsObjectAccess<'a, 'j, C> {
             self.prop_names.get(self.cx, self.idx)?;
 
         let value = self.input.get(self.cx, prop_name)?;
+        let key = prop_name.to_string(self.cx)?.value(self.cx);
+        let mut child_path = self.path.clone();
+        child_path.push(PathSegment::Key(key));
 
         self.idx += 1;
-        let mut de = Deserializer::new(self.cx, value);
-        let res = seed.deserialize(&mut de)?;
+        let mut de = Deserializer::with_path(self.cx, value, child_path.clone());
+        let res = seed
+            .deserialize(&mut de)
+            .map_err(|e| attach_path(e, &child_path))?;
         Ok(res)
     }
 }
 
+/// Checks whether `val` is an instance of the realm's global `ctor_name`
+/// constructor (e.g. `"Map"`/`"Set"`) by walking its prototype chain, the
+/// same check the JS `instanceof` operator performs. Comparing `val`'s own
+/// `constructor` property instead would miss subclasses (whose own
+/// `constructor` points at the subclass, not `ctor_name`) and would
+/// false-positive on a plain object carrying an own `constructor` property.
+#[cfg(feature = "napi-6")]
+fn is_instance_of<'j, C: Context<'j>>(
+    cx: &mut C,
+    val: Handle<'j, JsObject>,
+    ctor_name: &str,
+) -> LibResult<bool> {
+    let ctor: Handle<JsObject> = cx.global(ctor_name)?;
+    let proto: Handle<JsObject> = ctor.get(cx, "prototype")?;
+    let is_prototype_of: Handle<JsFunction> = proto.get(cx, "isPrototypeOf")?;
+    let result: Handle<JsBoolean> = is_prototype_of.call_with(cx).this(proto).arg(val).apply(cx)?;
+    Ok(result.value(cx))
+}
+
+/// Copies a `Buffer`/`ArrayBuffer`/`Uint8Array`-like value into an owned
+/// `Vec<u8>`, used by `deserialize_bytes`/`deserialize_byte_buf`.
+#[cfg(feature = "napi-6")]
+fn copy_byte_buffer<'j, C: Context<'j>, B: Value + TypedArray<Item = u8>>(
+    cx: &mut C,
+    buff: Handle<'j, B>,
+) -> LibResult<Vec<u8>> {
+    let guard = cx.lock();
+    match buff.try_borrow(&guard) {
+        Ok(buff_copy) => Ok(Vec::from(buff_copy.deref())),
+        Err(_) => errors::NotImplemented {
+            name: "unimplemented Deserializer::deserialize_bytes",
+        }
+        .fail()?,
+    }
+}
+
+/// Feeds a numeric typed array (e.g. `Int32Array`) into a `Visitor` as a
+/// sequence, so a target like `Vec<i32>` deserializes each element through
+/// the normal `JsNumber` deserialize path instead of requiring callers to
+/// spread the typed array into a plain JS array first.
+#[cfg(feature = "napi-6")]
+fn typed_array_seq<'x, 'j, C, B, V>(
+    cx: &mut C,
+    arr: Handle<'j, B>,
+    path: Vec<PathSegment>,
+    visitor: V,
+) -> LibResult<V::Value>
+where
+    C: Context<'j>,
+    B: Value + TypedArray,
+    f64: From<B::Item>,
+    V: Visitor<'x>,
+{
+    let values: Vec<f64> = {
+        let guard = cx.lock();
+        match arr.try_borrow(&guard) {
+            Ok(slice) => slice.iter().copied().map(f64::from).collect(),
+            Err(_) => errors::NotImplemented {
+                name: "unimplemented Deserializer::deserialize_seq",
+            }
+            .fail()?,
+        }
+    };
+    let mut deserializer = JsNumberSeqAccess::new(cx, values, path);
+    visitor.visit_seq(&mut deserializer)
+}
+
+#[doc(hidden)]
+#[cfg(feature = "napi-6")]
+struct JsNumberSeqAccess<'a, 'j, C: Context<'j> + 'a> {
+    cx: &'a mut C,
+    values: Vec<f64>,
+    idx: usize,
+    path: Vec<PathSegment>,
+}
+
+#[doc(hidden)]
+#[cfg(feature = "napi-6")]
+impl<'a, 'j, C: Context<'j>> JsNumberSeqAccess<'a, 'j, C> {
+    fn new(cx: &'a mut C, values: Vec<f64>, path: Vec<PathSegment>) -> Self {
+        JsNumberSeqAccess {
+            cx,
+            values,
+            idx: 0,
+            path,
+        }
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "napi-6")]
+impl<'x, 'a, 'j, C: Context<'j>> SeqAccess<'x> for JsNumberSeqAccess<'a, 'j, C> {
+    type Error = LibError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> LibResult<Option<T::Value>>
+    where
+        T: DeserializeSeed<'x>,
+    {
+        if self.idx >= self.values.len() {
+            return Ok(None);
+        }
+        let v = self.values[self.idx];
+        let mut child_path = self.path.clone();
+        child_path.push(PathSegment::Index(self.idx as u32));
+        self.idx += 1;
+        let handle = self.cx.number(v).upcast::<JsValue>();
+        let mut de = Deserializer::with_path(self.cx, handle, child_path.clone());
+        seed.deserialize(&mut de)
+            .map(Some)
+            .map_err(|e| attach_path(e, &child_path))
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "napi-6")]
+struct JsMapAccess<'a, 'j, C: Context<'j> + 'a> {
+    cx: &'a mut C,
+    iterator: Handle<'j, JsObject>,
+    pending_value: Option<Handle<'j, JsValue>>,
+    idx: u32,
+    path: Vec<PathSegment>,
+}
+
+#[doc(hidden)]
+#[cfg(feature = "napi-6")]
+impl<'a, 'j, C: Context<'j>> JsMapAccess<'a, 'j, C> {
+    fn new(cx: &'a mut C, input: Handle<'j, JsObject>, path: Vec<PathSegment>) -> LibResult<Self> {
+        let entries: Handle<JsFunction> = input.get(cx, "entries")?;
+        let iterator: Handle<JsObject> = entries.call_with(cx).this(input).apply(cx)?;
+        Ok(JsMapAccess {
+            cx,
+            iterator,
+            pending_value: None,
+            idx: 0,
+            path,
+        })
+    }
+
+    fn advance(&mut self) -> LibResult<Option<(Handle<'j, JsValue>, Handle<'j, JsValue>)>> {
+        let next: Handle<JsFunction> = self.iterator.get(self.cx, "next")?;
+        let result: Handle<JsObject> = next.call_with(self.cx).this(self.iterator).apply(self.cx)?;
+        let done: Handle<JsBoolean> = result.get(self.cx, "done")?;
+        if done.value(self.cx) {
+            return Ok(None);
+        }
+        let entry: Handle<JsArray> = result.get(self.cx, "value")?;
+        let key = entry.get(self.cx, 0)?;
+        let value = entry.get(self.cx, 1)?;
+        Ok(Some((key, value)))
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "napi-6")]
+impl<'x, 'a, 'j, C: Context<'j>> MapAccess<'x> for JsMapAccess<'a, 'j, C> {
+    type Error = LibError;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error>
+    where
+        K: DeserializeSeed<'x>,
+    {
+        match self.advance()? {
+            Some((key, value)) => {
+                self.pending_value = Some(value);
+                let mut de = Deserializer::new(self.cx, key);
+                seed.deserialize(&mut de).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Self::Error>
+    where
+        V: DeserializeSeed<'x>,
+    {
+        let value = self
+            .pending_value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        let mut child_path = self.path.clone();
+        child_path.push(PathSegment::Index(self.idx));
+        self.idx += 1;
+        let mut de = Deserializer::with_path(self.cx, value, child_path.clone());
+        seed.deserialize(&mut de)
+            .map_err(|e| attach_path(e, &child_path))
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "napi-6")]
+struct JsSetAccess<'a, 'j, C: Context<'j> + 'a> {
+    cx: &'a mut C,
+    iterator: Handle<'j, JsObject>,
+    idx: u32,
+    path: Vec<PathSegment>,
+}
+
+#[doc(hidden)]
+#[cfg(feature = "napi-6")]
+impl<'a, 'j, C: Context<'j>> JsSetAccess<'a, 'j, C> {
+    fn new(cx: &'a mut C, input: Handle<'j, JsObject>, path: Vec<PathSegment>) -> LibResult<Self> {
+        let values: Handle<JsFunction> = input.get(cx, "values")?;
+        let iterator: Handle<JsObject> = values.call_with(cx).this(input).apply(cx)?;
+        Ok(JsSetAccess {
+            cx,
+            iterator,
+            idx: 0,
+            path,
+        })
+    }
+}
+
+#[doc(hidden)]
+#[cfg(feature = "napi-6")]
+impl<'x, 'a, 'j, C: Context<'j>> SeqAccess<'x> for JsSetAccess<'a, 'j, C> {
+    type Error = LibError;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> LibResult<Option<T::Value>>
+    where
+        T: DeserializeSeed<'x>,
+    {
+        let next: Handle<JsFunction> = self.iterator.get(self.cx, "next")?;
+        let result: Handle<JsObject> = next.call_with(self.cx).this(self.iterator).apply(self.cx)?;
+        let done: Handle<JsBoolean> = result.get(self.cx, "done")?;
+        if done.value(self.cx) {
+            return Ok(None);
+        }
+        let value: Handle<JsValue> = result.get(self.cx, "value")?;
+        let mut child_path = self.path.clone();
+        child_path.push(PathSegment::Index(self.idx));
+        self.idx += 1;
+        let mut de = Deserializer::with_path(self.cx, value, child_path.clone());
+        seed.deserialize(&mut de)
+            .map(Some)
+            .map_err(|e| attach_path(e, &child_path))
+    }
+}
+
 #[doc(hidden)]
 struct JsEnumAccess<'a, 'j, C: Context<'j> + 'a> {
     cx: &'a mut C,
@@ -538,7 +1360,7 @@ impl<'x, 'a, 'j, C: Context<'j>> VariantAccess<'x> for JsVariantAccess<'a, 'j, C
         match self.value {
             Some(handle) => {
                 if let Ok(val) = handle.downcast::<JsArray, C>(self.cx) {
-                    let mut deserializer = JsArrayAccess::new(self.cx, val);
+                    let mut deserializer = JsArrayAccess::new(self.cx, val, Vec::new());
                     visitor.visit_seq(&mut deserializer)
                 } else {
                     Err(serde::de::Error::invalid_type(
@@ -566,7 +1388,7 @@ impl<'x, 'a, 'j, C: Context<'j>> VariantAccess<'x> for JsVariantAccess<'a, 'j, C
         match self.value {
             Some(handle) => {
                 if let Ok(val) = handle.downcast::<JsObject, C>(self.cx) {
-                    let mut deserializer = JsObjectAccess::new(self.cx, val)?;
+                    let mut deserializer = JsObjectAccess::new(self.cx, val, Vec::new())?;
                     visitor.visit_map(&mut deserializer)
                 } else {
                     Err(serde::de::Error::invalid_type(
@@ -590,7 +1412,7 @@ impl<'x, 'a, 'j, C: Context<'j>> VariantAccess<'x> for JsVariantAccess<'a, 'j, C
         match self.value {
             Some(handle) => {
                 if let Ok(val) = handle.downcast::<JsArray>() {
-                    let mut deserializer = JsArrayAccess::new(self.cx, val);
+                    let mut deserializer = JsArrayAccess::new(self.cx, val, Vec::new());
                     visitor.visit_seq(&mut deserializer)
                 } else {
                     Err(serde::de::Error::invalid_type(
@@ -618,7 +1440,7 @@ impl<'x, 'a, 'j, C: Context<'j>> VariantAccess<'x> for JsVariantAccess<'a, 'j, C
         match self.value {
             Some(handle) => {
                 if let Ok(val) = handle.downcast::<JsObject>() {
-                    let mut deserializer = JsObjectAccess::new(self.cx, val)?;
+                    let mut deserializer = JsObjectAccess::new(self.cx, val, Vec::new())?;
                     visitor.visit_map(&mut deserializer)
                 } else {
                     Err(serde::de::Error::invalid_type(
@@ -634,3 +1456,103 @@ impl<'x, 'a, 'j, C: Context<'j>> VariantAccess<'x> for JsVariantAccess<'a, 'j, C
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        attach_path, integral_in_half_open_range, integral_in_range, render_path, PathSegment,
+    };
+    use serde::de::Error as _;
+
+    #[test]
+    fn integral_in_range_accepts_bounds_inclusive() {
+        assert!(integral_in_range(
+            f64::from(u8::MIN),
+            f64::from(u8::MIN),
+            f64::from(u8::MAX)
+        ));
+        assert!(integral_in_range(
+            f64::from(u8::MAX),
+            f64::from(u8::MIN),
+            f64::from(u8::MAX)
+        ));
+        assert!(integral_in_range(
+            f64::from(i8::MIN),
+            f64::from(i8::MIN),
+            f64::from(i8::MAX)
+        ));
+    }
+
+    #[test]
+    fn integral_in_range_rejects_out_of_bounds_and_fractional() {
+        assert!(!integral_in_range(
+            f64::from(u8::MAX) + 1.0,
+            f64::from(u8::MIN),
+            f64::from(u8::MAX)
+        ));
+        assert!(!integral_in_range(-1.0, f64::from(u8::MIN), f64::from(u8::MAX)));
+        assert!(!integral_in_range(1.5, f64::from(u8::MIN), f64::from(u8::MAX)));
+    }
+
+    #[test]
+    fn integral_in_half_open_range_accepts_lower_bound_and_interior() {
+        assert!(integral_in_half_open_range(0.0, 0.0, 2f64.powi(64)));
+        // 2^64 - 2048 is the largest f64 below 2^64 (2^64 - 1 rounds up to 2^64).
+        assert!(integral_in_half_open_range(
+            2f64.powi(64) - 2048.0,
+            0.0,
+            2f64.powi(64)
+        ));
+        assert!(integral_in_half_open_range(
+            i64::MIN as f64,
+            i64::MIN as f64,
+            2f64.powi(63)
+        ));
+    }
+
+    #[test]
+    fn integral_in_half_open_range_rejects_upper_bound_and_below_min() {
+        assert!(!integral_in_half_open_range(2f64.powi(64), 0.0, 2f64.powi(64)));
+        assert!(!integral_in_half_open_range(-1.0, 0.0, 2f64.powi(64)));
+        assert!(!integral_in_half_open_range(1.5, 0.0, 2f64.powi(64)));
+    }
+
+    #[test]
+    fn render_path_renders_keys_and_indices() {
+        let path = vec![
+            PathSegment::Key("config".to_string()),
+            PathSegment::Key("servers".to_string()),
+            PathSegment::Index(2),
+            PathSegment::Key("port".to_string()),
+        ];
+        assert_eq!(render_path(&path), "config.servers[2].port");
+    }
+
+    #[test]
+    fn render_path_empty_is_empty_string() {
+        assert_eq!(render_path(&[]), "");
+    }
+
+    #[test]
+    fn attach_path_leaves_empty_path_untouched() {
+        let err = super::LibError::custom("boom");
+        let attached = attach_path(err, &[]);
+        assert_eq!(attached.to_string(), "boom");
+    }
+
+    #[test]
+    fn attach_path_prefixes_the_rendered_path() {
+        let err = super::LibError::custom("boom");
+        let path = vec![PathSegment::Key("config".to_string()), PathSegment::Index(2)];
+        let attached = attach_path(err, &path);
+        assert_eq!(attached.to_string(), "at config[2]: boom");
+    }
+
+    #[test]
+    fn attach_path_does_not_rewrap_an_already_located_error() {
+        let err = super::LibError::custom("at inner: boom");
+        let path = vec![PathSegment::Key("outer".to_string())];
+        let attached = attach_path(err, &path);
+        assert_eq!(attached.to_string(), "at inner: boom");
+    }
+}
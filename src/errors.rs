@@ -0,0 +1,64 @@
+//!
+//! Error types produced while converting between `JsValue`s and Rust values
+//!
+
+use snafu::Snafu;
+use std::fmt;
+
+/// The error type returned by [`crate::de`]'s `Deserializer`.
+#[derive(Debug, Snafu)]
+#[snafu(visibility(pub))]
+pub enum Error {
+    /// A code path that isn't implemented yet was hit.
+    #[snafu(display("{name}"))]
+    #[snafu(context(suffix(false)))]
+    NotImplemented { name: &'static str },
+
+    /// A JS object key couldn't be used as a Rust enum/map key.
+    #[snafu(display("invalid key type: {key}"))]
+    InvalidKeyType { key: String },
+
+    /// An index was read past the end of a `JsObjectAccess`'s property list.
+    #[snafu(display("array index {index} out of bounds for length {length}"))]
+    ArrayIndexOutOfBounds { length: u32, index: u32 },
+
+    /// A `BigInt` value didn't fit losslessly into the requested Rust
+    /// integer width. An out-of-range or fractional `Number` is reported
+    /// as an `invalid_type` [`Error::Message`] instead.
+    #[snafu(display("{name}"))]
+    NumberTooLarge { name: &'static str },
+
+    /// A `JsDate` couldn't be converted (e.g. its time value is `NaN`).
+    #[snafu(display("{reason}"))]
+    InvalidDate { reason: &'static str },
+
+    /// A `serde::de::Error::custom`/`invalid_type`-style message that
+    /// doesn't map to one of the variants above.
+    #[snafu(display("{message}"))]
+    Message { message: String },
+
+    /// A JS exception was thrown while reading a value (e.g. calling
+    /// `toISOString`/`entries` on an object).
+    #[snafu(display("{source}"))]
+    Js { source: neon::result::Throw },
+}
+
+impl serde::de::Error for Error {
+    fn custom<T>(msg: T) -> Self
+    where
+        T: fmt::Display,
+    {
+        Error::Message {
+            message: msg.to_string(),
+        }
+    }
+}
+
+impl From<neon::result::Throw> for Error {
+    fn from(source: neon::result::Throw) -> Self {
+        Error::Js { source }
+    }
+}
+
+/// A `Result` alias defaulting to [`Error`], mirroring [`crate::de::Deserializer`]'s `Error` type.
+pub type Result<T, E = Error> = std::result::Result<T, E>;